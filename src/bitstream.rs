@@ -93,18 +93,105 @@ impl BitStream {
             nbits -= 1;
         }
     }
+
+    /// Iterates the written bytes one bit at a time, most-significant bit
+    /// of each byte first.
+    pub fn bits_be(&self) -> BitIteratorBE<'_> {
+        BitIteratorBE::new(self.as_ref())
+    }
+
+    /// Iterates the written bytes one bit at a time, least-significant bit
+    /// of each byte first.
+    pub fn bits_le(&self) -> BitIteratorLE<'_> {
+        BitIteratorLE::new(self.as_ref())
+    }
+}
+
+/// Iterates a byte slice one bit at a time, most-significant bit first
+/// within each byte, without going through the Gorilla decoder.
+pub struct BitIteratorBE<'a> {
+    data: &'a [u8],
+    byte_idx: usize,
+    bit_idx: u8,
+}
+
+impl<'a> BitIteratorBE<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            byte_idx: 0,
+            bit_idx: 0,
+        }
+    }
+}
+
+impl<'a> std::iter::Iterator for BitIteratorBE<'a> {
+    type Item = Bit;
+
+    fn next(&mut self) -> Option<Bit> {
+        let byte = *self.data.get(self.byte_idx)?;
+        let bit = Bit::from((byte >> (7 - self.bit_idx)) & 1 == 1);
+
+        self.bit_idx += 1;
+        if self.bit_idx == 8 {
+            self.bit_idx = 0;
+            self.byte_idx += 1;
+        }
+
+        Some(bit)
+    }
+}
+
+/// Iterates a byte slice one bit at a time, least-significant bit first
+/// within each byte, without going through the Gorilla decoder.
+pub struct BitIteratorLE<'a> {
+    data: &'a [u8],
+    byte_idx: usize,
+    bit_idx: u8,
+}
+
+impl<'a> BitIteratorLE<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            byte_idx: 0,
+            bit_idx: 0,
+        }
+    }
 }
 
+impl<'a> std::iter::Iterator for BitIteratorLE<'a> {
+    type Item = Bit;
+
+    fn next(&mut self) -> Option<Bit> {
+        let byte = *self.data.get(self.byte_idx)?;
+        let bit = Bit::from((byte >> self.bit_idx) & 1 == 1);
+
+        self.bit_idx += 1;
+        if self.bit_idx == 8 {
+            self.bit_idx = 0;
+            self.byte_idx += 1;
+        }
+
+        Some(bit)
+    }
+}
+
+/// A cursor over a borrowed bit stream.
+///
+/// Unlike an owned reader, `Reader<'a>` never copies the underlying bytes:
+/// it just walks `&'a [u8]` directly, so handing out an iterator over a
+/// chunk's body costs nothing beyond the `Reader` struct itself.
 #[derive(Debug, Default)]
-pub struct Reader {
-    stream: bytes::BytesMut,
+pub struct Reader<'a> {
+    stream: &'a [u8],
     stream_offset: usize,
     buffer: u64,
     valid: u8,
 }
 
-impl Reader {
-    pub fn new(stream: bytes::BytesMut) -> Self {
+impl<'a> Reader<'a> {
+    pub fn new(stream: &'a [u8]) -> Self {
         Self {
             stream,
             ..Default::default()
@@ -187,11 +274,11 @@ impl Reader {
     }
 
     pub fn load_next_buffer(&mut self, nbits: u8) -> bool {
-        if self.stream_offset >= self.stream.as_ref().len() {
+        if self.stream_offset >= self.stream.len() {
             return false;
         }
 
-        if self.stream_offset + 8 < self.stream.as_ref().len() {
+        if self.stream_offset + 8 < self.stream.len() {
             self.buffer = u64::from_be_bytes(
                 self.stream[self.stream_offset..self.stream_offset + 8]
                     .try_into()
@@ -203,14 +290,13 @@ impl Reader {
         }
 
         let mut nbytes = (nbits / 8 + 1) as usize;
-        if self.stream_offset + nbytes > self.stream.as_ref().len() {
-            nbytes = self.stream.as_ref().len() - self.stream_offset;
+        if self.stream_offset + nbytes > self.stream.len() {
+            nbytes = self.stream.len() - self.stream_offset;
         }
 
         let mut buffer = 0_u64;
         for i in 0..nbytes {
-            buffer |=
-                ((self.stream.as_ref()[self.stream_offset + i]) as u64) << (8 * (nbytes - i - 1));
+            buffer |= (self.stream[self.stream_offset + i] as u64) << (8 * (nbytes - i - 1));
         }
 
         self.buffer = buffer;
@@ -219,9 +305,55 @@ impl Reader {
 
         true
     }
+
+    /// Snapshot of the cursor state: `(stream_offset, buffer, valid)`.
+    ///
+    /// Paired with [`Reader::restore`] so callers (e.g. a checkpointing
+    /// iterator) can rewind to a previously visited position without
+    /// re-reading the stream from the start.
+    pub fn checkpoint(&self) -> (usize, u64, u8) {
+        (self.stream_offset, self.buffer, self.valid)
+    }
+
+    /// Restores a cursor position previously obtained from [`Reader::checkpoint`].
+    pub fn restore(&mut self, stream_offset: usize, buffer: u64, valid: u8) {
+        self.stream_offset = stream_offset;
+        self.buffer = buffer;
+        self.valid = valid;
+    }
+
+    /// Bytes of the stream that have not yet been pulled into the bit buffer.
+    ///
+    /// Note this does not count the (up to 64) bits already buffered but
+    /// unread; it is meant as a cheap "are we near the end" probe, not an
+    /// exact remaining-bit count.
+    pub fn remaining(&self) -> usize {
+        self.stream.len() - self.stream_offset
+    }
+
+    /// True once both the byte stream and the bit buffer are exhausted.
+    pub fn is_eof(&self) -> bool {
+        self.remaining() == 0 && self.valid == 0
+    }
+
+    /// Iterates the bytes not yet pulled into the bit buffer (see
+    /// [`Reader::remaining`]) one bit at a time, most-significant bit of
+    /// each byte first, bypassing the Gorilla decoder entirely. Bits already
+    /// buffered but not yet read are not included.
+    pub fn bits_be(&self) -> BitIteratorBE<'a> {
+        BitIteratorBE::new(&self.stream[self.stream_offset..])
+    }
+
+    /// Iterates the bytes not yet pulled into the bit buffer (see
+    /// [`Reader::remaining`]) one bit at a time, least-significant bit of
+    /// each byte first, bypassing the Gorilla decoder entirely. Bits already
+    /// buffered but not yet read are not included.
+    pub fn bits_le(&self) -> BitIteratorLE<'a> {
+        BitIteratorLE::new(&self.stream[self.stream_offset..])
+    }
 }
 
-impl std::io::Read for Reader {
+impl<'a> std::io::Read for Reader<'a> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         match self.read_byte() {
             Some(val) => {
@@ -252,7 +384,7 @@ mod tests {
             bs.write_bits(v, 29);
         }
 
-        let mut r = Reader::new(bytes::BytesMut::from(bs.as_ref()));
+        let mut r = Reader::new(bs.as_ref());
 
         for i in [true, false] {
             let value = r.read_bit().unwrap();
@@ -269,4 +401,49 @@ mod tests {
             assert_eq!(i as u64, value, "testing read_bit_fast");
         }
     }
+
+    #[test]
+    pub fn test_bit_iterators() {
+        // 0b1011_0010, 0x00: exercises both a mid-byte pattern and a
+        // byte-boundary crossing into an all-zero byte.
+        let data = [0b1011_0010_u8, 0x00];
+
+        let be: Vec<bool> = BitIteratorBE::new(&data).map(bool::from).collect();
+        assert_eq!(
+            be,
+            vec![
+                true, false, true, true, false, false, true, false, false, false, false, false,
+                false, false, false, false,
+            ]
+        );
+
+        let le: Vec<bool> = BitIteratorLE::new(&data).map(bool::from).collect();
+        assert_eq!(
+            le,
+            vec![
+                false, true, false, false, true, true, false, true, false, false, false, false,
+                false, false, false, false,
+            ]
+        );
+
+        let mut bs = BitStream::new();
+        bs.write_bits(0b1011_0010, 8);
+        bs.write_bits(0x00, 8);
+
+        // write_byte always appends a trailing speculative zero byte, so
+        // bs.as_ref() is 3 bytes (24 bits) even though only 16 were written;
+        // only the first 16 bits are meaningful here.
+        assert_eq!(
+            bs.bits_be().map(bool::from).take(16).collect::<Vec<_>>(),
+            be
+        );
+        assert_eq!(
+            bs.bits_le().map(bool::from).take(16).collect::<Vec<_>>(),
+            le
+        );
+
+        let r = Reader::new(bs.as_ref());
+        assert_eq!(r.bits_be().map(bool::from).take(16).collect::<Vec<_>>(), be);
+        assert_eq!(r.bits_le().map(bool::from).take(16).collect::<Vec<_>>(), le);
+    }
 }
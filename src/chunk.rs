@@ -6,12 +6,33 @@ use unsigned_varint::io;
 
 const CHUNK_COMPACT_CAPACITY_THRESHOLD: usize = 32;
 const MAX_VARINT_LEN64: usize = 10;
+const MAX_VARINT_LEN32: usize = 5;
+// How often `XORIterator` snapshots its decoder state while scanning forward.
+// Smaller means faster seeks but more memory for checkpoints; larger is the
+// opposite. Samples 0 and 1 are never checkpointed since they use the
+// special full-timestamp/varint encodings rather than delta-of-delta.
+const DEFAULT_CHECKPOINT_INTERVAL: u16 = 128;
 
 type Result<T> = std::result::Result<T, Error>;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Encoding {
-    XOR,
-    None,
+    None = 0,
+    XOR = 1,
+}
+
+impl Encoding {
+    fn to_byte(self) -> u8 {
+        self as u8
+    }
+
+    fn from_byte(b: u8) -> Result<Self> {
+        match b {
+            0 => Ok(Encoding::None),
+            1 => Ok(Encoding::XOR),
+            other => Err(Error::UnknownEncoding(other)),
+        }
+    }
 }
 
 pub trait Appender {
@@ -37,7 +58,7 @@ pub trait Chunk<'a> {
 
     fn appender(&'a mut self) -> Result<Box<dyn Appender + 'a>>;
 
-    fn iterator(&self) -> Box<dyn Iterator>;
+    fn iterator(&'a self) -> Box<dyn Iterator + 'a>;
 }
 
 pub struct NopIterator {}
@@ -58,6 +79,16 @@ impl Iterator for NopIterator {
 
 pub struct XORChunk {
     b: BitStream,
+    // Whether `self.b.count` reflects a real partial-byte write cursor.
+    // `from_bytes` has no way to recover how many of the last byte's bits
+    // were actually written (the on-disk format doesn't carry that), so it
+    // always sets `count: 0`; treating that as "last byte is full" would
+    // make the next `append()` start a fresh byte in the middle of the
+    // logical bitstream instead of packing into the real free bits,
+    // corrupting every sample decoded after it. Chunks rebuilt from bytes
+    // are therefore iterator-only: `appender_state()` refuses to hand out
+    // an appender for them instead of silently producing corrupt output.
+    appendable: bool,
 }
 
 impl XORChunk {
@@ -68,6 +99,7 @@ impl XORChunk {
 
         Self {
             b: BitStream { data, count: 0 },
+            appendable: true,
         }
     }
 
@@ -75,9 +107,90 @@ impl XORChunk {
         self.b.to_vec()
     }
 
-    fn _iterator(&self) -> XORIterator {
+    /// Rebuilds a chunk from bytes previously produced by `to_vec`/`bytes`
+    /// (the `[num_samples:u16][bitstream]` layout `XORChunk` itself writes).
+    /// The result is iterator-only: `appender()`/`appender_state()` return
+    /// `Error::NotAppendable` on it. The original `BitStream`'s partial-byte
+    /// write cursor isn't preserved across serialization, so resuming writes
+    /// would silently corrupt every sample appended afterward instead of
+    /// packing into the real free bits of the last stored byte.
+    ///
+    /// Fails with `Error::Truncated` if `data` is shorter than the 2-byte
+    /// `num_samples` header every other method on this type assumes is
+    /// present; without this check, callers outside `chunk::decode_from`
+    /// (which this type exists to support) could panic the library just by
+    /// handing it bytes read from elsewhere.
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self> {
+        if data.len() < 2 {
+            return Err(Error::Truncated);
+        }
+
+        Ok(Self {
+            b: BitStream {
+                data: BytesMut::from(data.as_slice()),
+                count: 0,
+            },
+            appendable: false,
+        })
+    }
+
+    /// Like `iterator()`, but returns a `std::iter::Iterator` instead of
+    /// the crate's own `Iterator` trait object, so it works with
+    /// `.collect()`/`.filter()`/`.zip()` and friends.
+    pub fn iter_std(&self) -> StdXORIterator<'_> {
+        self._iterator().into_iter()
+    }
+
+    /// Replays the chunk to recover the scalar Gorilla encoder state it left
+    /// off at, without taking `self` by mutable reference. Pair with
+    /// `appender_from_state` to get a usable `XORAppender` back: splitting
+    /// the replay out like this lets a caller that rehydrates an appender
+    /// repeatedly (e.g. `series::ChunkSeriesAppender`) do the O(n) replay
+    /// once and cache the result, instead of paying it on every call.
+    ///
+    /// Returns `Error::NotAppendable` for a chunk rebuilt by `from_bytes`,
+    /// since its partial-byte write cursor was never preserved and resuming
+    /// writes on it would corrupt the bitstream.
+    pub fn appender_state(&self) -> Result<AppenderState> {
+        if !self.appendable {
+            return Err(Error::NotAppendable);
+        }
+
+        let mut it = self._iterator();
+
+        while it.next()? {}
+
+        let leading = if self.num_samples() == 0 {
+            0xff
+        } else {
+            it.leading
+        };
+
+        Ok(AppenderState {
+            t: it.t,
+            v: it.v,
+            t_delta: it.t_delta,
+            leading,
+            trailing: it.trailing,
+        })
+    }
+
+    /// Rehydrates an `XORAppender` from a previously captured
+    /// `AppenderState`, without replaying the chunk's existing samples.
+    pub fn appender_from_state(&mut self, state: AppenderState) -> XORAppender<'_> {
+        XORAppender {
+            b: &mut self.b,
+            t: state.t,
+            v: state.v,
+            t_delta: state.t_delta,
+            leading: state.leading,
+            trailing: state.trailing,
+        }
+    }
+
+    fn _iterator<'b>(&'b self) -> XORIterator<'b> {
         XORIterator {
-            br: Reader::new(bytes::BytesMut::from(&self.b.as_ref()[2..])),
+            br: Reader::new(&self.b.as_ref()[2..]),
             num_total: u16::from_be_bytes(self.b.as_ref()[0..2].try_into().unwrap()),
             t: i64::MIN,
             num_read: 0,
@@ -85,6 +198,8 @@ impl XORChunk {
             leading: 0,
             trailing: 0,
             t_delta: 0,
+            checkpoints: Vec::new(),
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
         }
     }
 }
@@ -112,29 +227,11 @@ impl<'a> Chunk<'a> for XORChunk {
     }
 
     fn appender(&'a mut self) -> Result<Box<dyn Appender + 'a>> {
-        let mut it = self._iterator();
-
-        while it.next()? {}
-
-        let leading = if u16::from_be_bytes(self.b.as_ref().try_into().unwrap()) == 0 {
-            0xff
-        } else {
-            it.leading
-        };
-
-        let a = XORAppender {
-            b: &mut self.b,
-            t: it.t,
-            v: it.v,
-            t_delta: it.t_delta,
-            leading,
-            trailing: it.trailing,
-        };
-
-        Ok(Box::new(a))
+        let state = self.appender_state()?;
+        Ok(Box::new(self.appender_from_state(state)))
     }
 
-    fn iterator(&self) -> Box<dyn Iterator> {
+    fn iterator(&'a self) -> Box<dyn Iterator + 'a> {
         Box::new(self._iterator())
     }
 }
@@ -148,7 +245,33 @@ pub struct XORAppender<'a> {
     trailing: u8,
 }
 
+/// A `Copy` snapshot of the scalar state behind an `XORAppender`, with the
+/// `&mut BitStream` borrow stripped out. Obtained via
+/// `XORChunk::appender_state`/`XORAppender::state` and turned back into a
+/// live appender via `XORChunk::appender_from_state`.
+#[derive(Debug, Clone, Copy)]
+pub struct AppenderState {
+    t: i64,
+    v: f64,
+    t_delta: u64,
+    leading: u8,
+    trailing: u8,
+}
+
 impl<'a> XORAppender<'a> {
+    /// Captures the current scalar encoder state, decoupled from this
+    /// appender's borrow of the chunk's `BitStream`, so it can be cached and
+    /// used to rehydrate a fresh appender later without replaying.
+    pub fn state(&self) -> AppenderState {
+        AppenderState {
+            t: self.t,
+            v: self.v,
+            t_delta: self.t_delta,
+            leading: self.leading,
+            trailing: self.trailing,
+        }
+    }
+
     fn write_v_delta(&mut self, v: f64) {
         let v_delta = v.to_bits() ^ self.v.to_bits();
 
@@ -189,7 +312,10 @@ impl<'a> Appender for XORAppender<'a> {
     fn append(&mut self, t: i64, v: f64) {
         let mut t_delta = 0_u64;
         let n = u16::from_be_bytes(self.b.as_ref()[0..2].as_ref().try_into().unwrap());
-        // TODO(detailyang): check the u16 overflow
+        // A single chunk's sample counter is a u16 and overflows past 65535
+        // (`n + 1` below panics in debug builds and wraps in release);
+        // callers who need more samples than that should append through
+        // `series::ChunkSeries`, which rolls over to a new chunk instead.
 
         if n == 0 {
             let mut buf = [0_u8; MAX_VARINT_LEN64];
@@ -249,9 +375,26 @@ fn bit_range(x: i64, nbits: u8) -> bool {
     return -((1 << (nbits - 1)) - 1) <= x && x <= 1 << (nbits - 1);
 }
 
+/// A snapshot of the Gorilla decoder state taken right after a sample has
+/// been fully decoded, so resuming from it always lands on a clean sample
+/// boundary. Paired with `Reader::checkpoint`/`Reader::restore` to also pin
+/// down the bit-level cursor.
+#[derive(Debug, Clone, Copy)]
+struct Checkpoint {
+    ts: i64,
+    t_delta: u64,
+    v: f64,
+    leading: u8,
+    trailing: u8,
+    stream_offset: usize,
+    buffer: u64,
+    valid: u8,
+    num_read: u16,
+}
+
 #[derive(Debug)]
-pub struct XORIterator {
-    br: Reader,
+pub struct XORIterator<'a> {
+    br: Reader<'a>,
     num_total: u16,
     num_read: u16,
     t: i64,
@@ -259,9 +402,11 @@ pub struct XORIterator {
     leading: u8,
     trailing: u8,
     t_delta: u64,
+    checkpoints: Vec<Checkpoint>,
+    checkpoint_interval: u16,
 }
 
-impl XORIterator {
+impl<'a> XORIterator<'a> {
     pub fn read_value(&mut self) -> Result<bool> {
         let b = self.br.try_read_bit().ok_or(crate::error::Error::EOF)?;
         if b == Bit::Zero {
@@ -298,14 +443,51 @@ impl XORIterator {
         self.num_read += 1;
         Ok(true)
     }
-}
 
-impl Iterator for XORIterator {
-    fn next(&mut self) -> Result<bool> {
-        if self.num_read == self.num_total {
-            return Ok(false);
+    fn push_checkpoint(&mut self) {
+        let (stream_offset, buffer, valid) = self.br.checkpoint();
+        self.checkpoints.push(Checkpoint {
+            ts: self.t,
+            t_delta: self.t_delta,
+            v: self.v,
+            leading: self.leading,
+            trailing: self.trailing,
+            stream_offset,
+            buffer,
+            valid,
+            num_read: self.num_read,
+        });
+    }
+
+    fn restore_checkpoint(&mut self, cp: Checkpoint) {
+        self.br.restore(cp.stream_offset, cp.buffer, cp.valid);
+        self.t = cp.ts;
+        self.t_delta = cp.t_delta;
+        self.v = cp.v;
+        self.leading = cp.leading;
+        self.trailing = cp.trailing;
+        self.num_read = cp.num_read;
+    }
+
+    fn nearest_checkpoint(&self, t: i64) -> Option<Checkpoint> {
+        match self.checkpoints.binary_search_by(|cp| cp.ts.cmp(&t)) {
+            Ok(idx) => Some(self.checkpoints[idx]),
+            Err(0) => None,
+            Err(idx) => Some(self.checkpoints[idx - 1]),
         }
+    }
 
+    fn reset(&mut self) {
+        self.br.restore(0, 0, 0);
+        self.t = i64::MIN;
+        self.v = 0.0;
+        self.leading = 0;
+        self.trailing = 0;
+        self.t_delta = 0;
+        self.num_read = 0;
+    }
+
+    fn decode_next(&mut self) -> Result<bool> {
         if self.num_read == 0 {
             let t = read_i64(&mut self.br)?;
             let v = self.br.read_bits(64).ok_or(crate::error::Error::EOF)?;
@@ -368,10 +550,41 @@ impl Iterator for XORIterator {
 
         self.read_value()
     }
+}
+
+impl<'a> Iterator for XORIterator<'a> {
+    fn next(&mut self) -> Result<bool> {
+        if self.num_read == self.num_total {
+            return Ok(false);
+        }
+
+        let ok = self.decode_next()?;
+
+        // Never checkpoint samples 0/1: they use the full-timestamp/varint
+        // encodings, not delta-of-delta, so resuming from them wouldn't land
+        // on a clean delta-of-delta boundary.
+        if ok && self.num_read >= 3 && self.num_read.is_multiple_of(self.checkpoint_interval) {
+            self.push_checkpoint();
+        }
+
+        Ok(ok)
+    }
 
     fn seek(&mut self, t: i64) -> Result<bool> {
-        while t > self.t || self.num_read == 0 {
-            self.next()?;
+        // No early-exit on `self.t >= t` here: that's true for *any* t at or
+        // before the current sample, including ones the cursor has already
+        // overshot, so it would silently refuse to seek backwards. Always
+        // restore from the nearest checkpoint (or the very start) and decode
+        // forward so backward seeks work too.
+        match self.nearest_checkpoint(t) {
+            Some(cp) => self.restore_checkpoint(cp),
+            None => self.reset(),
+        }
+
+        while self.num_read == 0 || self.t < t {
+            if !self.next()? {
+                return Ok(false);
+            }
         }
 
         Ok(true)
@@ -382,6 +595,153 @@ impl Iterator for XORIterator {
     }
 }
 
+/// Adapts `XORIterator`'s `Result`-returning `next` to `std::iter::Iterator`
+/// so it composes with `.collect()`/`.filter()`/`.zip()` and the rest of
+/// the standard combinators. Iteration is fused: once it runs out of
+/// samples or hits a decode error it yields `None` forever after, so check
+/// `last_error()` to tell the two cases apart.
+pub struct StdXORIterator<'a> {
+    inner: XORIterator<'a>,
+    last_error: Option<Error>,
+    done: bool,
+}
+
+impl<'a> StdXORIterator<'a> {
+    pub fn last_error(&self) -> Option<&Error> {
+        self.last_error.as_ref()
+    }
+}
+
+impl<'a> std::iter::Iterator for StdXORIterator<'a> {
+    type Item = (i64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match Iterator::next(&mut self.inner) {
+            Ok(true) => Some(self.inner.at()),
+            Ok(false) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.last_error = Some(e);
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<'a> std::iter::FusedIterator for StdXORIterator<'a> {}
+
+impl<'a> std::iter::IntoIterator for XORIterator<'a> {
+    type Item = (i64, f64);
+    type IntoIter = StdXORIterator<'a>;
+
+    fn into_iter(self) -> StdXORIterator<'a> {
+        StdXORIterator {
+            inner: self,
+            last_error: None,
+            done: false,
+        }
+    }
+}
+
+/// Magic identifying a framed chunk container on disk.
+const MAGIC: [u8; 4] = *b"CKE1";
+const FORMAT_VERSION: u8 = 1;
+const MIN_CONTAINER_LEN: usize = MAGIC.len() + 1 /* version */ + 1 /* encoding */ + 4 /* crc32c */;
+
+/// A chunk rebuilt by `decode_from`, tagged with the encoding it was
+/// stored under. `None` carries no chunk data: it is the sentinel
+/// encoding for a deliberately empty/absent series.
+pub enum DecodedChunk {
+    XOR(XORChunk),
+    None,
+}
+
+/// Writes `chunk` as a self-describing, checksummed container:
+/// `[magic:4][version:1][encoding:1][num_samples:varint][body][crc32c:4]`,
+/// where `body` is `chunk.bytes()` and the trailing CRC32 (Castagnoli)
+/// covers everything before it. This makes the bytes safe to store or ship
+/// as a standalone blob instead of relying on the encoding being known
+/// out-of-band.
+pub fn encode_to<W: std::io::Write>(chunk: &XORChunk, w: &mut W) -> Result<()> {
+    let mut buf = Vec::with_capacity(chunk.bytes().len() + MIN_CONTAINER_LEN + MAX_VARINT_LEN32);
+    buf.extend_from_slice(&MAGIC);
+    buf.push(FORMAT_VERSION);
+    buf.push(Encoding::XOR.to_byte());
+
+    let mut varint_buf = [0_u8; MAX_VARINT_LEN32];
+    buf.extend_from_slice(unsigned_varint::encode::u32(
+        chunk.num_samples() as u32,
+        &mut varint_buf,
+    ));
+
+    buf.extend_from_slice(chunk.bytes());
+
+    let crc = crc32c::crc32c(&buf);
+    w.write_all(&buf).map_err(Error::Io)?;
+    w.write_all(&crc.to_be_bytes()).map_err(Error::Io)?;
+
+    Ok(())
+}
+
+/// Reads back a container written by `encode_to`, validating the magic,
+/// version and CRC32 before dispatching on the encoding byte to build the
+/// matching chunk type.
+pub fn decode_from<R: std::io::Read>(r: &mut R) -> Result<DecodedChunk> {
+    let mut raw = Vec::new();
+    r.read_to_end(&mut raw).map_err(Error::Io)?;
+
+    if raw.len() < MIN_CONTAINER_LEN {
+        return Err(Error::Truncated);
+    }
+
+    if raw[0..4] != MAGIC {
+        return Err(Error::BadMagic);
+    }
+
+    let version = raw[4];
+    if version != FORMAT_VERSION {
+        return Err(Error::UnsupportedVersion(version));
+    }
+
+    let encoding = Encoding::from_byte(raw[5])?;
+
+    let (header_and_body, crc_bytes) = raw.split_at(raw.len() - 4);
+    let expected_crc = u32::from_be_bytes(crc_bytes.try_into().unwrap());
+    let actual_crc = crc32c::crc32c(header_and_body);
+    if actual_crc != expected_crc {
+        return Err(Error::ChecksumMismatch {
+            expected: expected_crc,
+            actual: actual_crc,
+        });
+    }
+
+    // Bound the varint read to the header region (at most
+    // `MAX_VARINT_LEN32` bytes past the encoding byte) so it can never run
+    // on into the body, let alone past `header_and_body`.
+    let varint_region_end = (6 + MAX_VARINT_LEN32).min(header_and_body.len());
+    let mut num_samples_reader = std::io::Cursor::new(&header_and_body[6..varint_region_end]);
+    io::read_u32(&mut num_samples_reader).map_err(Error::ReadError)?;
+    let body_start = 6 + num_samples_reader.position() as usize;
+
+    if body_start > header_and_body.len() {
+        return Err(Error::Truncated);
+    }
+
+    let body = &header_and_body[body_start..];
+
+    match encoding {
+        Encoding::XOR => Ok(DecodedChunk::XOR(XORChunk::from_bytes(body.to_vec())?)),
+        Encoding::None => Ok(DecodedChunk::None),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -456,4 +816,195 @@ mod tests {
 
         assert_eq!(exp, exp1);
     }
+
+    #[test]
+    pub fn test_seek_matches_linear_scan() {
+        let mut xor = XORChunk::new();
+        let mut a = xor.appender().unwrap();
+
+        let mut ts = Vec::new();
+        let mut t = 1000_i64;
+        for i in 0..2000 {
+            t += 10;
+            a.append(t, i as f64);
+            ts.push(t);
+        }
+        std::mem::drop(a);
+
+        let mut it = xor.iterator();
+
+        // A mix of forward, backward and out-of-range targets, in that
+        // order, so the backward jumps actually exercise the
+        // nearest-checkpoint restore rather than a coincidental forward scan.
+        for &target in &[ts[1500], ts[10], ts[0] - 1, ts[1999], ts[500]] {
+            assert!(it.seek(target).unwrap());
+            let (seeked_ts, _) = it.at();
+
+            let expected = *ts.iter().find(|&&t| t >= target).unwrap();
+            assert_eq!(
+                expected, seeked_ts,
+                "seek({}) landed on the wrong sample",
+                target
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_encode_decode_round_trip() {
+        let mut xor = XORChunk::new();
+        let mut a = xor.appender().unwrap();
+        for i in 0..100 {
+            a.append(1000 + i, i as f64);
+        }
+        std::mem::drop(a);
+
+        let mut buf = Vec::new();
+        encode_to(&xor, &mut buf).unwrap();
+
+        let mut r = buf.as_slice();
+        match decode_from(&mut r).unwrap() {
+            DecodedChunk::XOR(decoded) => assert_eq!(xor.bytes(), decoded.bytes()),
+            DecodedChunk::None => panic!("expected an XOR chunk"),
+        }
+    }
+
+    #[test]
+    pub fn test_appender_rejected_after_round_trip() {
+        // A chunk rebuilt from bytes has no way to recover the original
+        // BitStream's partial-byte write cursor, so resuming `appender()`
+        // on it would silently corrupt every sample appended afterward
+        // instead of just failing loudly.
+        let mut xor = XORChunk::new();
+        let mut a = xor.appender().unwrap();
+        for i in 0..5 {
+            a.append(1000 + i, i as f64);
+        }
+        std::mem::drop(a);
+
+        let mut exp = Vec::new();
+        {
+            let mut it = xor.iterator();
+            while it.next().unwrap() {
+                exp.push(it.at());
+            }
+        }
+
+        let reloaded = XORChunk::from_bytes(xor.to_vec()).unwrap();
+        assert!(matches!(
+            reloaded.appender_state(),
+            Err(Error::NotAppendable)
+        ));
+
+        let mut got = Vec::new();
+        let mut it = reloaded.iterator();
+        while it.next().unwrap() {
+            got.push(it.at());
+        }
+        assert_eq!(exp, got);
+    }
+
+    #[test]
+    pub fn test_from_bytes_rejects_short_input() {
+        // `from_bytes` is `pub` and reachable without going through
+        // `decode_from`'s pre-validation, so it must reject a buffer
+        // shorter than the 2-byte num_samples header itself instead of
+        // panicking the first time `num_samples()`/`iterator()` touches it.
+        assert!(matches!(
+            XORChunk::from_bytes(vec![]),
+            Err(Error::Truncated)
+        ));
+        assert!(matches!(
+            XORChunk::from_bytes(vec![0]),
+            Err(Error::Truncated)
+        ));
+    }
+
+    #[test]
+    pub fn test_decode_checksum_mismatch() {
+        let xor = XORChunk::new();
+        let mut buf = Vec::new();
+        encode_to(&xor, &mut buf).unwrap();
+
+        let last = buf.len() - 1;
+        buf[last] ^= 0xff;
+
+        let mut r = buf.as_slice();
+        assert!(matches!(
+            decode_from(&mut r),
+            Err(Error::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    pub fn test_decode_truncated() {
+        let buf = vec![0_u8; MIN_CONTAINER_LEN - 1];
+        let mut r = buf.as_slice();
+        assert!(matches!(decode_from(&mut r), Err(Error::Truncated)));
+    }
+
+    #[test]
+    pub fn test_decode_xor_body_too_short() {
+        // A hand-built container whose header/CRC are all self-consistent
+        // but whose XOR body is empty (below the 2-byte num_samples
+        // minimum XORChunk requires): decode_from must reject it instead
+        // of handing back a chunk that panics on first use.
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&MAGIC);
+        buf.push(FORMAT_VERSION);
+        buf.push(Encoding::XOR.to_byte());
+        buf.push(0); // num_samples varint: 0, no body bytes follow
+        let crc = crc32c::crc32c(&buf);
+        buf.extend_from_slice(&crc.to_be_bytes());
+
+        let mut r = buf.as_slice();
+        assert!(matches!(decode_from(&mut r), Err(Error::Truncated)));
+    }
+
+    #[test]
+    pub fn test_decode_bad_magic() {
+        let xor = XORChunk::new();
+        let mut buf = Vec::new();
+        encode_to(&xor, &mut buf).unwrap();
+        buf[0] = b'X';
+
+        let mut r = buf.as_slice();
+        assert!(matches!(decode_from(&mut r), Err(Error::BadMagic)));
+    }
+
+    #[test]
+    pub fn test_std_iterator_collect() {
+        let mut xor = XORChunk::new();
+        let mut a = xor.appender().unwrap();
+
+        let mut exp = Vec::new();
+        let mut ts = 0_i64;
+        for i in 0..50 {
+            ts += 5;
+            a.append(ts, i as f64);
+            exp.push((ts, i as f64));
+        }
+        std::mem::drop(a);
+
+        let got: Vec<(i64, f64)> = xor.iter_std().collect();
+        assert_eq!(exp, got);
+    }
+
+    #[test]
+    pub fn test_std_iterator_last_error_on_truncated_stream() {
+        let mut xor = XORChunk::new();
+        let mut a = xor.appender().unwrap();
+        a.append(1, 1.0);
+        a.append(2, 2.0);
+        a.append(3, 3.0);
+        std::mem::drop(a);
+
+        let mut bytes = xor.bytes().to_vec();
+        bytes.truncate(3); // header plus a single body byte: not even the first sample fits
+        let truncated = XORChunk::from_bytes(bytes).unwrap();
+
+        let mut it = truncated.iter_std();
+        assert_eq!(None, it.next());
+        assert!(it.last_error().is_some());
+        assert_eq!(None, it.next(), "iteration must stay fused after an error");
+    }
 }
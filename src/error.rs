@@ -9,4 +9,18 @@ pub enum Error {
     ReadError(ReadError),
     #[error("append over u16::MAX")]
     AppendOverflow,
+    #[error("io error: {0}")]
+    Io(std::io::Error),
+    #[error("truncated chunk container")]
+    Truncated,
+    #[error("bad chunk magic")]
+    BadMagic,
+    #[error("unsupported chunk format version: {0}")]
+    UnsupportedVersion(u8),
+    #[error("unknown chunk encoding: {0}")]
+    UnknownEncoding(u8),
+    #[error("chunk checksum mismatch: expected {expected:#010x}, got {actual:#010x}")]
+    ChecksumMismatch { expected: u32, actual: u32 },
+    #[error("chunk rebuilt from bytes has no appendable bit cursor; iterate it instead")]
+    NotAppendable,
 }
@@ -15,15 +15,13 @@
 //!         appender.append(2_i64, 3.0);
 //!         appender.append(3_i64, 4.0);
 //!     }
-//!     let mut it = chunk.iterator();
-//!     {
-//!         while it.next().unwrap_or_else(||false) {
-//!             let (ts, val) = it.at();
-//!         }
-//!     }
+//!     drop(appender);
+//!     let samples: Vec<(i64, f64)> = chunk.iter_std().collect();
+//!     assert_eq!(samples, vec![(1, 2.0), (2, 3.0), (3, 4.0)]);
 //! }
 
 pub mod bitstream;
 pub mod chunk;
 pub mod error;
 mod helper;
+pub mod series;
@@ -0,0 +1,366 @@
+//! A [`ChunkSeries`] chains `XORChunk`s end-to-end so callers never have to
+//! think about the two-byte sample-count ceiling of a single chunk (see the
+//! `TODO` that used to live on `XORAppender::append`): once the active chunk
+//! is full, it is sealed and a fresh one takes over, with the Gorilla state
+//! starting clean (`leading = 0xff`, new timestamp baseline) the same way a
+//! brand new `XORChunk` already does.
+
+use crate::chunk::{Appender, AppenderState, Chunk, Iterator, XORChunk};
+use crate::error::Error;
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// Sample-count ceiling matching the two-byte counter a single chunk stores
+/// its sample count in.
+pub const DEFAULT_MAX_SAMPLES: u16 = 65535;
+
+/// The highest timestamp appended to a chunk so far. Tracked alongside each
+/// chunk (cheap: every timestamp passes through `ChunkSeriesAppender::append`
+/// anyway) so `ChunkSeriesIterator::seek` can jump straight to the chunk
+/// that could contain a target timestamp instead of linearly decoding every
+/// earlier chunk to rule it out.
+#[derive(Debug, Clone, Copy)]
+struct ChunkRange {
+    max: i64,
+}
+
+pub struct ChunkSeries {
+    chunks: Vec<XORChunk>,
+    // Parallel to `chunks`; `None` for a chunk nothing has been appended to
+    // yet (only ever the trailing, just-rolled-over chunk).
+    ranges: Vec<Option<ChunkRange>>,
+    max_samples: u16,
+    max_bytes: Option<usize>,
+    rollover: bool,
+}
+
+impl ChunkSeries {
+    pub fn new() -> Self {
+        Self {
+            chunks: vec![XORChunk::new()],
+            ranges: vec![None],
+            max_samples: DEFAULT_MAX_SAMPLES,
+            max_bytes: None,
+            rollover: true,
+        }
+    }
+
+    /// Caps the number of samples per chunk before it is sealed. Values
+    /// above `DEFAULT_MAX_SAMPLES` are pointless: the chunk's own counter
+    /// can't hold more.
+    pub fn with_max_samples(mut self, max_samples: u16) -> Self {
+        self.max_samples = max_samples;
+        self
+    }
+
+    /// Caps the encoded byte size of a chunk before it is sealed, in
+    /// addition to the sample-count cap.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Disables automatic rollover: once the active chunk is full,
+    /// `append` returns `Error::AppendOverflow` instead of starting a new one.
+    pub fn without_rollover(mut self) -> Self {
+        self.rollover = false;
+        self
+    }
+
+    pub fn chunks(&self) -> &[XORChunk] {
+        &self.chunks
+    }
+
+    pub fn num_samples(&self) -> usize {
+        self.chunks.iter().map(|c| c.num_samples()).sum()
+    }
+
+    pub fn appender(&mut self) -> Result<ChunkSeriesAppender<'_>> {
+        let state = self
+            .chunks
+            .last()
+            .expect("ChunkSeries always has an active chunk")
+            .appender_state()?;
+
+        Ok(ChunkSeriesAppender {
+            series: self,
+            state,
+        })
+    }
+
+    pub fn iterator(&self) -> ChunkSeriesIterator<'_> {
+        ChunkSeriesIterator {
+            chunks: &self.chunks,
+            ranges: &self.ranges,
+            chunk_idx: 0,
+            current: self.chunks.first().map(|c| c.iterator()),
+        }
+    }
+
+    fn active_is_full(&self) -> bool {
+        let active = self
+            .chunks
+            .last()
+            .expect("ChunkSeries always has an active chunk");
+
+        active.num_samples() >= self.max_samples as usize
+            || self
+                .max_bytes
+                .is_some_and(|max| active.bytes().len() >= max)
+    }
+}
+
+impl Default for ChunkSeries {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Appends samples across a `ChunkSeries`, transparently rolling over to a
+/// new chunk once the active one is full. Unlike `chunk::Appender::append`
+/// this can fail: with rollover disabled, appending into a full series
+/// returns `Error::AppendOverflow` instead of overflowing the active
+/// chunk's sample counter.
+///
+/// Holds the active chunk's `AppenderState` rather than a live
+/// `chunk::Appender`, since the latter would need to borrow the chunk it
+/// appends into for as long as this struct lives, which conflicts with
+/// `ChunkSeries` owning that chunk in a `Vec` it must also be able to push
+/// onto for rollover. Caching the (`Copy`, borrow-free) state instead means
+/// each `append` call only replays the chunk once, on rollover, rather than
+/// on every sample.
+pub struct ChunkSeriesAppender<'a> {
+    series: &'a mut ChunkSeries,
+    state: AppenderState,
+}
+
+impl<'a> ChunkSeriesAppender<'a> {
+    pub fn append(&mut self, t: i64, v: f64) -> Result<()> {
+        if self.series.active_is_full() {
+            if !self.series.rollover {
+                return Err(Error::AppendOverflow);
+            }
+            self.series.chunks.push(XORChunk::new());
+            self.series.ranges.push(None);
+            self.state = self
+                .series
+                .chunks
+                .last()
+                .expect("just pushed an active chunk")
+                .appender_state()?;
+        }
+
+        let chunk = self
+            .series
+            .chunks
+            .last_mut()
+            .expect("just ensured an active chunk exists");
+        let mut appender = chunk.appender_from_state(self.state);
+        appender.append(t, v);
+        self.state = appender.state();
+
+        let range = self
+            .series
+            .ranges
+            .last_mut()
+            .expect("just ensured an active chunk exists");
+        *range = Some(match range {
+            Some(r) => ChunkRange { max: r.max.max(t) },
+            None => ChunkRange { max: t },
+        });
+
+        Ok(())
+    }
+}
+
+pub struct ChunkSeriesIterator<'a> {
+    chunks: &'a [XORChunk],
+    ranges: &'a [Option<ChunkRange>],
+    chunk_idx: usize,
+    current: Option<Box<dyn Iterator + 'a>>,
+}
+
+impl<'a> ChunkSeriesIterator<'a> {
+    fn advance_chunk(&mut self) {
+        self.chunk_idx += 1;
+        self.current = self.chunks.get(self.chunk_idx).map(|c| c.iterator());
+    }
+
+    /// The index of the earliest chunk that could contain `t`: the first
+    /// chunk whose range hasn't been entirely left behind by `t` (its `max`
+    /// is still `>= t`), or whichever chunk has no range yet (nothing
+    /// appended to it so far). Falls back to the last chunk if `t` is past
+    /// every recorded range. Letting `seek` jump straight here means it
+    /// never has to linearly decode a chunk it already knows can't contain
+    /// `t` just to rule it out.
+    fn chunk_index_for(&self, t: i64) -> usize {
+        for (idx, range) in self.ranges.iter().enumerate() {
+            match range {
+                Some(r) if t <= r.max => return idx,
+                Some(_) => continue,
+                None => return idx,
+            }
+        }
+
+        self.chunks.len().saturating_sub(1)
+    }
+}
+
+impl<'a> Iterator for ChunkSeriesIterator<'a> {
+    fn next(&mut self) -> Result<bool> {
+        loop {
+            match &mut self.current {
+                Some(it) => {
+                    if it.next()? {
+                        return Ok(true);
+                    }
+                }
+                None => return Ok(false),
+            }
+            self.advance_chunk();
+        }
+    }
+
+    fn seek(&mut self, t: i64) -> Result<bool> {
+        // Jump straight to the chunk whose recorded range could contain `t`
+        // instead of always restarting from chunk 0: every earlier chunk's
+        // `max` is known to be `< t`, so decoding it first would only ever
+        // confirm what `ranges` already tells us. This also makes backward
+        // seeks work, since `chunk_index_for` doesn't assume `chunk_idx`
+        // only moves forward.
+        self.chunk_idx = self.chunk_index_for(t);
+        self.current = self.chunks.get(self.chunk_idx).map(|c| c.iterator());
+
+        loop {
+            match &mut self.current {
+                Some(it) => {
+                    if it.seek(t)? {
+                        return Ok(true);
+                    }
+                }
+                None => return Ok(false),
+            }
+            self.advance_chunk();
+        }
+    }
+
+    fn at(&self) -> (i64, f64) {
+        self.current
+            .as_ref()
+            .map(|it| it.at())
+            .unwrap_or((i64::MIN, 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rollover_at_max_samples() {
+        let mut series = ChunkSeries::new().with_max_samples(4);
+        let mut a = series.appender().unwrap();
+
+        for i in 0..10 {
+            a.append(i as i64, i as f64).unwrap();
+        }
+
+        assert_eq!(10, series.num_samples());
+        assert_eq!(3, series.chunks().len());
+        assert_eq!(4, series.chunks()[0].num_samples());
+        assert_eq!(4, series.chunks()[1].num_samples());
+        assert_eq!(2, series.chunks()[2].num_samples());
+    }
+
+    #[test]
+    fn test_rollover_at_max_bytes() {
+        let mut series = ChunkSeries::new().with_max_bytes(16);
+        let mut a = series.appender().unwrap();
+
+        for i in 0..200 {
+            a.append(i as i64 * 1000, i as f64).unwrap();
+        }
+
+        assert_eq!(200, series.num_samples());
+        assert!(series.chunks().len() > 1);
+    }
+
+    #[test]
+    fn test_without_rollover_overflows() {
+        let mut series = ChunkSeries::new().with_max_samples(2).without_rollover();
+        let mut a = series.appender().unwrap();
+
+        a.append(1, 1.0).unwrap();
+        a.append(2, 2.0).unwrap();
+
+        assert!(matches!(a.append(3, 3.0), Err(Error::AppendOverflow)));
+    }
+
+    #[test]
+    fn test_iterate_across_chunks() {
+        let mut series = ChunkSeries::new().with_max_samples(5);
+        let mut a = series.appender().unwrap();
+
+        let mut exp = Vec::new();
+        let mut ts = 0_i64;
+        for i in 0..23 {
+            ts += 7;
+            a.append(ts, i as f64).unwrap();
+            exp.push((ts, i as f64));
+        }
+
+        let mut got = Vec::new();
+        let mut it = series.iterator();
+        while it.next().unwrap() {
+            got.push(it.at());
+        }
+
+        assert_eq!(exp, got);
+    }
+
+    #[test]
+    fn test_seek_backward_across_chunk_boundary() {
+        let mut series = ChunkSeries::new().with_max_samples(3);
+        let mut a = series.appender().unwrap();
+
+        for i in 0..8 {
+            a.append(i as i64 * 10, i as f64).unwrap();
+        }
+
+        let mut it = series.iterator();
+        assert!(it.seek(65).unwrap());
+        assert_eq!((70, 7.0), it.at());
+
+        assert!(it.seek(5).unwrap());
+        assert_eq!((10, 1.0), it.at());
+    }
+
+    #[test]
+    fn test_seek_jumps_directly_to_containing_chunk() {
+        // Many small chunks so a seek that still had to linearly decode
+        // every earlier chunk to rule it out would be easy to get wrong:
+        // each target below lands in a different chunk than the previous
+        // one, including several jumps backward.
+        let mut series = ChunkSeries::new().with_max_samples(2);
+        let mut a = series.appender().unwrap();
+
+        let mut exp = Vec::new();
+        for i in 0..40 {
+            let ts = i as i64 * 10;
+            a.append(ts, i as f64).unwrap();
+            exp.push((ts, i as f64));
+        }
+
+        assert_eq!(20, series.chunks().len());
+
+        let mut it = series.iterator();
+        for &target in &[350_i64, 10, 385, 200, 0, 150] {
+            assert!(it.seek(target).unwrap());
+            let want = exp.iter().find(|&&(ts, _)| ts >= target).unwrap();
+            assert_eq!(*want, it.at());
+        }
+
+        // Past the last sample: no chunk contains it, so the seek fails.
+        assert!(!it.seek(1000).unwrap());
+    }
+}